@@ -0,0 +1,168 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BumpLevel {
+    Major,
+    Minor,
+    Patch,
+    Premajor,
+    Preminor,
+    Prepatch,
+    Prerelease(String),
+}
+
+#[derive(Debug)]
+pub enum BumpError {
+    ParseVersion(semver::Error),
+    InvalidPrereleaseIdentifier(semver::Error),
+}
+
+impl core::error::Error for BumpError {}
+
+impl core::fmt::Display for BumpError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ParseVersion(error) => error.fmt(f),
+            Self::InvalidPrereleaseIdentifier(error) => error.fmt(f),
+        }
+    }
+}
+
+#[inline]
+fn bump_prerelease(
+    identifier: &str,
+    current: &semver::Prerelease,
+) -> Result<semver::Prerelease, BumpError> {
+    let prefix = format!("{identifier}.");
+
+    let next = current
+        .as_str()
+        .strip_prefix(prefix.as_str())
+        .and_then(|suffix| suffix.parse::<u64>().ok())
+        .map_or(0, |n| n + 1);
+
+    semver::Prerelease::new(&format!("{identifier}.{next}"))
+        .map_err(BumpError::InvalidPrereleaseIdentifier)
+}
+
+#[inline]
+pub fn bump(current: &str, level: &BumpLevel) -> Result<String, BumpError> {
+    let mut version = semver::Version::parse(current).map_err(BumpError::ParseVersion)?;
+
+    match level {
+        BumpLevel::Major => {
+            version.major += 1;
+            version.minor = 0;
+            version.patch = 0;
+            version.pre = semver::Prerelease::EMPTY;
+        }
+        BumpLevel::Minor => {
+            version.minor += 1;
+            version.patch = 0;
+            version.pre = semver::Prerelease::EMPTY;
+        }
+        BumpLevel::Patch => {
+            version.patch += 1;
+            version.pre = semver::Prerelease::EMPTY;
+        }
+        BumpLevel::Premajor => {
+            version.major += 1;
+            version.minor = 0;
+            version.patch = 0;
+            version.pre = semver::Prerelease::EMPTY;
+            version.pre = bump_prerelease("rc", &version.pre)?;
+        }
+        BumpLevel::Preminor => {
+            version.minor += 1;
+            version.patch = 0;
+            version.pre = semver::Prerelease::EMPTY;
+            version.pre = bump_prerelease("rc", &version.pre)?;
+        }
+        BumpLevel::Prepatch => {
+            version.patch += 1;
+            version.pre = semver::Prerelease::EMPTY;
+            version.pre = bump_prerelease("rc", &version.pre)?;
+        }
+        BumpLevel::Prerelease(identifier) => {
+            version.pre = bump_prerelease(identifier, &version.pre)?;
+        }
+    }
+
+    version.build = semver::BuildMetadata::EMPTY;
+
+    Ok(version.to_string())
+}
+
+#[cfg(test)]
+mod test_bump {
+    use super::{BumpError, BumpLevel, bump};
+
+    #[test]
+    fn it_should_bump_patch() {
+        assert_eq!(bump("1.2.3", &BumpLevel::Patch).unwrap(), "1.2.4");
+    }
+
+    #[test]
+    fn it_should_bump_minor() {
+        assert_eq!(bump("1.2.3", &BumpLevel::Minor).unwrap(), "1.3.0");
+    }
+
+    #[test]
+    fn it_should_bump_major() {
+        assert_eq!(bump("1.2.3", &BumpLevel::Major).unwrap(), "2.0.0");
+    }
+
+    #[test]
+    fn it_should_clear_prerelease_on_plain_bump() {
+        assert_eq!(bump("1.2.3-rc.1", &BumpLevel::Patch).unwrap(), "1.2.4");
+    }
+
+    #[test]
+    fn it_should_bump_prepatch() {
+        assert_eq!(bump("1.2.3", &BumpLevel::Prepatch).unwrap(), "1.2.4-rc.0");
+    }
+
+    #[test]
+    fn it_should_increment_existing_prerelease() {
+        assert_eq!(
+            bump("1.2.3-rc.0", &BumpLevel::Prerelease("rc".to_string())).unwrap(),
+            "1.2.3-rc.1"
+        );
+    }
+
+    #[test]
+    fn it_should_reject_invalid_semver() {
+        assert!(bump("not-a-version", &BumpLevel::Patch).is_err());
+    }
+
+    #[test]
+    fn it_should_reset_prerelease_counter_on_prepatch() {
+        assert_eq!(
+            bump("1.3.0-rc.2", &BumpLevel::Prepatch).unwrap(),
+            "1.3.1-rc.0"
+        );
+    }
+
+    #[test]
+    fn it_should_reset_prerelease_counter_on_preminor() {
+        assert_eq!(
+            bump("1.3.0-rc.2", &BumpLevel::Preminor).unwrap(),
+            "1.4.0-rc.0"
+        );
+    }
+
+    #[test]
+    fn it_should_reset_prerelease_counter_on_premajor() {
+        assert_eq!(
+            bump("1.3.0-rc.2", &BumpLevel::Premajor).unwrap(),
+            "2.0.0-rc.0"
+        );
+    }
+
+    #[test]
+    fn it_should_reject_invalid_prerelease_identifier() {
+        let error = bump("1.2.3", &BumpLevel::Prerelease(String::new()))
+            .expect_err("it should return an error");
+
+        assert!(matches!(error, BumpError::InvalidPrereleaseIdentifier(_)));
+    }
+}
@@ -9,6 +9,11 @@ pub enum CargoTomlError {
     MissingPackageField { workspace: bool },
     MissingPackageVersionField { workspace: bool },
     ParseToml(Box<toml_edit::TomlError>),
+    InvalidCurrentVersion(crate::bump::BumpError),
+    MissingPackageNameField,
+    InvalidSemverVersion { workspace: bool },
+    MissingWorkspacePackageVersionForInheritance,
+    CannotBumpInheritedVersion,
 }
 
 impl core::error::Error for CargoTomlError {}
@@ -55,10 +60,38 @@ impl core::fmt::Display for CargoTomlError {
                 write!(f, "{field} field not found")
             }
             Self::InvalidWorkspaceFieldDataType => write!(f, "\"workspace\" is not a table"),
+            Self::InvalidCurrentVersion(error) => error.fmt(f),
+            Self::MissingPackageNameField => write!(f, "\"package.name\" field not found"),
+            Self::InvalidSemverVersion { workspace } => {
+                let field = if *workspace {
+                    "\"workspace.package.version\""
+                } else {
+                    "\"package.version\""
+                };
+
+                write!(f, "{field} is not valid semver")
+            }
+            Self::MissingWorkspacePackageVersionForInheritance => write!(
+                f,
+                "\"package.version\" inherits from \"workspace.package.version\", but it was not found"
+            ),
+            Self::CannotBumpInheritedVersion => write!(
+                f,
+                "\"package.version\" inherits from \"workspace.package.version\" and cannot be bumped directly; bump the workspace root instead"
+            ),
         }
     }
 }
 
+#[inline]
+fn is_inherited_workspace_version(version_key: &toml_edit::Item) -> bool {
+    version_key
+        .as_table_like()
+        .and_then(|table| table.get("workspace"))
+        .and_then(|workspace| workspace.as_bool())
+        .unwrap_or(false)
+}
+
 #[inline]
 fn set_package_version(
     package_table: &mut dyn toml_edit::TableLike,
@@ -69,6 +102,10 @@ fn set_package_version(
         .get("version")
         .ok_or(CargoTomlError::MissingPackageVersionField { workspace })?;
 
+    if is_inherited_workspace_version(version_key) {
+        return Ok(false);
+    }
+
     let version_key_str = version_key
         .as_str()
         .ok_or(CargoTomlError::InvalidPackageVersionFieldDataType { workspace })?;
@@ -87,6 +124,54 @@ fn set_package_version(
     Ok(modified)
 }
 
+#[inline]
+fn get_package_version(
+    package_table: &dyn toml_edit::TableLike,
+    workspace: bool,
+) -> Result<Option<String>, CargoTomlError> {
+    match package_table.get("version") {
+        Some(version_key) if is_inherited_workspace_version(version_key) => Ok(None),
+        Some(version_key) => {
+            let version_key_str = version_key
+                .as_str()
+                .ok_or(CargoTomlError::InvalidPackageVersionFieldDataType { workspace })?;
+
+            Ok(Some(version_key_str.to_owned()))
+        }
+        None => Ok(None),
+    }
+}
+
+#[inline]
+pub fn get_cargo_toml_version(contents: &str) -> Result<Option<String>, CargoTomlError> {
+    let document =
+        toml::parse(contents).map_err(|error| CargoTomlError::ParseToml(Box::new(error)))?;
+
+    if let Some(workspace) = document.get("workspace") {
+        let workspace_table = workspace
+            .as_table_like()
+            .ok_or(CargoTomlError::InvalidWorkspaceFieldDataType)?;
+
+        let package = workspace_table
+            .get("package")
+            .ok_or(CargoTomlError::MissingPackageField { workspace: true })?;
+
+        let package_table = package
+            .as_table_like()
+            .ok_or(CargoTomlError::InvalidPackageFieldDataType { workspace: true })?;
+
+        get_package_version(package_table, true)
+    } else if let Some(package_raw) = document.get("package") {
+        let package_table = package_raw
+            .as_table_like()
+            .ok_or(CargoTomlError::InvalidPackageFieldDataType { workspace: false })?;
+
+        get_package_version(package_table, false)
+    } else {
+        Err(CargoTomlError::MissingPackageField { workspace: false })
+    }
+}
+
 #[inline]
 pub fn set_cargo_toml_version(
     contents: String,
@@ -130,6 +215,221 @@ pub fn set_cargo_toml_version(
     Ok((modified, output))
 }
 
+#[inline]
+fn package_version_is_inherited(document: &toml_edit::DocumentMut) -> bool {
+    document
+        .get("package")
+        .and_then(toml_edit::Item::as_table_like)
+        .and_then(|table| table.get("version"))
+        .is_some_and(is_inherited_workspace_version)
+}
+
+#[inline]
+pub fn bump_cargo_toml_version(
+    contents: String,
+    level: &crate::bump::BumpLevel,
+) -> Result<(bool, String), CargoTomlError> {
+    let document =
+        toml::parse(&contents).map_err(|error| CargoTomlError::ParseToml(Box::new(error)))?;
+
+    let workspace = document.get("workspace").is_some();
+
+    if !workspace && package_version_is_inherited(&document) {
+        return Err(CargoTomlError::CannotBumpInheritedVersion);
+    }
+
+    let current_version = get_cargo_toml_version(&contents)?
+        .ok_or(CargoTomlError::MissingPackageVersionField { workspace })?;
+
+    let next_version =
+        crate::bump::bump(&current_version, level).map_err(CargoTomlError::InvalidCurrentVersion)?;
+
+    set_cargo_toml_version(contents, &next_version)
+}
+
+const DEPENDENCY_TABLE_KEYS: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+
+#[inline]
+fn reapply_requirement_operator(current: &str, version: &str) -> String {
+    for operator in ["^", "~", "="] {
+        if current.starts_with(operator) {
+            return format!("{operator}{version}");
+        }
+    }
+
+    version.to_string()
+}
+
+#[inline]
+fn set_dependency_version_in_table(
+    table: &mut dyn toml_edit::TableLike,
+    dependency: &str,
+    version: &str,
+) -> bool {
+    let Some(entry) = table.get_mut(dependency) else {
+        return false;
+    };
+
+    if let Some(current) = entry.as_str() {
+        let next = reapply_requirement_operator(current, version);
+
+        if next == current {
+            return false;
+        }
+
+        *entry = toml_edit::Item::Value(toml_edit::Value::String(toml_edit::Formatted::new(next)));
+
+        return true;
+    }
+
+    let Some(dependency_table) = entry.as_table_like_mut() else {
+        return false;
+    };
+
+    let Some(current_version) = dependency_table.get("version").and_then(|v| v.as_str()) else {
+        return false;
+    };
+
+    let next = reapply_requirement_operator(current_version, version);
+
+    if next == current_version {
+        return false;
+    }
+
+    dependency_table.insert(
+        "version",
+        toml_edit::Item::Value(toml_edit::Value::String(toml_edit::Formatted::new(next))),
+    );
+
+    true
+}
+
+#[inline]
+pub fn set_cargo_toml_dependency_version(
+    contents: String,
+    dependency: &str,
+    version: &str,
+) -> Result<(bool, String), CargoTomlError> {
+    let mut document =
+        toml::parse(&contents).map_err(|error| CargoTomlError::ParseToml(Box::new(error)))?;
+
+    let mut modified = false;
+
+    for key in DEPENDENCY_TABLE_KEYS {
+        if let Some(table) = document
+            .get_mut(key)
+            .and_then(toml_edit::Item::as_table_like_mut)
+        {
+            modified |= set_dependency_version_in_table(table, dependency, version);
+        }
+    }
+
+    if let Some(workspace) = document
+        .get_mut("workspace")
+        .and_then(toml_edit::Item::as_table_like_mut)
+    {
+        for key in DEPENDENCY_TABLE_KEYS {
+            if let Some(table) = workspace
+                .get_mut(key)
+                .and_then(toml_edit::Item::as_table_like_mut)
+            {
+                modified |= set_dependency_version_in_table(table, dependency, version);
+            }
+        }
+    }
+
+    let output = if modified {
+        toml::serialize(&document)
+    } else {
+        contents
+    };
+
+    Ok((modified, output))
+}
+
+#[inline]
+fn validate_package_table(
+    package_table: &dyn toml_edit::TableLike,
+    workspace: bool,
+    workspace_version_available: Option<bool>,
+    errors: &mut Vec<CargoTomlError>,
+) {
+    if !workspace && package_table.get("name").is_none() {
+        errors.push(CargoTomlError::MissingPackageNameField);
+    }
+
+    match package_table.get("version") {
+        None => errors.push(CargoTomlError::MissingPackageVersionField { workspace }),
+        Some(version_key) if is_inherited_workspace_version(version_key) => {
+            if workspace_version_available == Some(false) {
+                errors.push(CargoTomlError::MissingWorkspacePackageVersionForInheritance);
+            }
+        }
+        Some(version_key) => match version_key.as_str() {
+            None => errors.push(CargoTomlError::InvalidPackageVersionFieldDataType { workspace }),
+            Some(version) => {
+                if semver::Version::parse(version).is_err() {
+                    errors.push(CargoTomlError::InvalidSemverVersion { workspace });
+                }
+            }
+        },
+    }
+}
+
+#[inline]
+pub fn validate_cargo_toml(contents: &str) -> Result<Vec<CargoTomlError>, CargoTomlError> {
+    let document =
+        toml::parse(contents).map_err(|error| CargoTomlError::ParseToml(Box::new(error)))?;
+
+    let mut errors = Vec::new();
+
+    let has_workspace_section = document.get("workspace").is_some();
+    let mut has_workspace_package_version = false;
+
+    if let Some(workspace) = document.get("workspace") {
+        let workspace_table = workspace
+            .as_table_like()
+            .ok_or(CargoTomlError::InvalidWorkspaceFieldDataType)?;
+
+        if let Some(package) = workspace_table.get("package") {
+            let package_table = package
+                .as_table_like()
+                .ok_or(CargoTomlError::InvalidPackageFieldDataType { workspace: true })?;
+
+            validate_package_table(package_table, true, None, &mut errors);
+
+            has_workspace_package_version = package_table.get("version").is_some();
+        }
+    }
+
+    if let Some(package_raw) = document.get("package") {
+        let package_table = package_raw
+            .as_table_like()
+            .ok_or(CargoTomlError::InvalidPackageFieldDataType { workspace: false })?;
+
+        // Only this document's own `[workspace.package]` can be checked here; a member
+        // manifest inheriting from a sibling workspace root has no way to know whether
+        // that root's version exists, so we don't flag it.
+        let workspace_version_available =
+            has_workspace_section.then_some(has_workspace_package_version);
+
+        validate_package_table(
+            package_table,
+            false,
+            workspace_version_available,
+            &mut errors,
+        );
+    } else if has_workspace_section {
+        if !has_workspace_package_version {
+            errors.push(CargoTomlError::MissingPackageVersionField { workspace: true });
+        }
+    } else {
+        errors.push(CargoTomlError::MissingPackageField { workspace: false });
+    }
+
+    Ok(errors)
+}
+
 #[inline]
 fn cargo_update_lock_file_command() -> std::process::Command {
     let mut cmd = std::process::Command::new("cargo");
@@ -142,6 +442,327 @@ pub fn update_lock_files(dir: &std::path::Path) -> std::io::Result<bool> {
     run_update_lock_file_command(cargo_update_lock_file_command(), dir)
 }
 
+#[cfg(test)]
+mod test_get_cargo_toml_version {
+    use super::{CargoTomlError, get_cargo_toml_version};
+    use crate::package_managers::error::PackageManagerError;
+
+    #[test]
+    fn it_should_return_package_version() {
+        let input = r#"[package]
+version = "1.2.3"
+edition = "2024"
+"#;
+
+        let version = get_cargo_toml_version(input)
+            .expect("it not to raise")
+            .expect("it to return a version");
+
+        assert_eq!(version, "1.2.3");
+    }
+
+    #[test]
+    fn it_should_return_workspace_package_version() {
+        let input = r#"[workspace]
+members = ["cli"]
+
+[workspace.package]
+version = "4.5.6"
+"#;
+
+        let version = get_cargo_toml_version(input)
+            .expect("it not to raise")
+            .expect("it to return a version");
+
+        assert_eq!(version, "4.5.6");
+    }
+
+    #[test]
+    fn it_should_return_none_when_version_is_missing() {
+        let input = "[package]\nedition = \"2024\"\n";
+
+        let version = get_cargo_toml_version(input).expect("it not to raise");
+
+        assert!(version.is_none());
+    }
+
+    #[test]
+    fn it_should_return_none_when_version_is_workspace_inherited() {
+        let input = "[package]\nname = \"my-lib\"\nversion.workspace = true\n";
+
+        let version = get_cargo_toml_version(input).expect("it not to raise");
+
+        assert!(version.is_none());
+    }
+
+    #[test]
+    fn it_should_require_package_field() {
+        let input = "";
+
+        let result = get_cargo_toml_version(input).expect_err("it should return an error");
+
+        assert!(matches!(
+            result,
+            CargoTomlError::MissingPackageField { workspace: false }
+        ));
+
+        assert!(
+            crate::error::Error::from(PackageManagerError::from(result))
+                .to_string()
+                .contains("\"package\"")
+        );
+    }
+
+    #[test]
+    fn package_version_should_be_string() {
+        let input = "[package.version]\nkey = \"123\"\n";
+
+        let result = get_cargo_toml_version(input).expect_err("it should return an error");
+
+        assert!(matches!(
+            result,
+            CargoTomlError::InvalidPackageVersionFieldDataType { workspace: false }
+        ));
+
+        assert!(
+            crate::error::Error::from(PackageManagerError::from(result))
+                .to_string()
+                .contains("\"package.version\"")
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_bump_cargo_toml_version {
+    use super::bump_cargo_toml_version;
+    use crate::bump::BumpLevel;
+
+    #[test]
+    fn it_should_bump_patch_version() {
+        let input = "[package]\nversion = \"1.2.3\"\nedition = \"2024\"\n";
+
+        let (modified, output) =
+            bump_cargo_toml_version(input.to_string(), &BumpLevel::Patch).expect("it not to raise");
+
+        assert!(modified);
+
+        assert!(output.contains("version = \"1.2.4\""));
+    }
+
+    #[test]
+    fn it_should_bump_workspace_package_version() {
+        let input = "[workspace.package]\nversion = \"1.2.3\"\n";
+
+        let (modified, output) =
+            bump_cargo_toml_version(input.to_string(), &BumpLevel::Major).expect("it not to raise");
+
+        assert!(modified);
+
+        assert!(output.contains("version = \"2.0.0\""));
+    }
+
+    #[test]
+    fn it_should_reject_bumping_inherited_version() {
+        let input = "[package]\nname = \"my-lib\"\nversion.workspace = true\n";
+
+        let result = bump_cargo_toml_version(input.to_string(), &BumpLevel::Patch)
+            .expect_err("it should return an error");
+
+        assert!(matches!(
+            result,
+            super::CargoTomlError::CannotBumpInheritedVersion
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_validate_cargo_toml {
+    use super::{CargoTomlError, validate_cargo_toml};
+
+    #[test]
+    fn it_should_return_no_errors_for_a_valid_manifest() {
+        let input = "[package]\nname = \"my-lib\"\nversion = \"1.2.3\"\n";
+
+        let errors = validate_cargo_toml(input).expect("it not to raise");
+
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn it_should_allow_workspace_inherited_version() {
+        let input = "[package]\nname = \"my-lib\"\nversion.workspace = true\n";
+
+        let errors = validate_cargo_toml(input).expect("it not to raise");
+
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn it_should_flag_missing_name() {
+        let input = "[package]\nversion = \"1.2.3\"\n";
+
+        let errors = validate_cargo_toml(input).expect("it not to raise");
+
+        assert!(matches!(
+            errors.as_slice(),
+            [CargoTomlError::MissingPackageNameField]
+        ));
+    }
+
+    #[test]
+    fn it_should_flag_invalid_semver() {
+        let input = "[package]\nname = \"my-lib\"\nversion = \"not-semver\"\n";
+
+        let errors = validate_cargo_toml(input).expect("it not to raise");
+
+        assert!(matches!(
+            errors.as_slice(),
+            [CargoTomlError::InvalidSemverVersion { workspace: false }]
+        ));
+    }
+
+    #[test]
+    fn it_should_collect_multiple_errors_at_once() {
+        let input = "[package]\nversion = \"not-semver\"\n";
+
+        let errors = validate_cargo_toml(input).expect("it not to raise");
+
+        assert_eq!(errors.len(), 2);
+
+        assert!(matches!(
+            errors.as_slice(),
+            [
+                CargoTomlError::MissingPackageNameField,
+                CargoTomlError::InvalidSemverVersion { workspace: false }
+            ]
+        ));
+    }
+
+    #[test]
+    fn it_should_flag_workspace_root_without_any_version() {
+        let input = "[workspace]\nmembers = [\"cli\"]\n";
+
+        let errors = validate_cargo_toml(input).expect("it not to raise");
+
+        assert!(matches!(
+            errors.as_slice(),
+            [CargoTomlError::MissingPackageVersionField { workspace: true }]
+        ));
+    }
+
+    #[test]
+    fn it_should_allow_workspace_root_with_workspace_package_version() {
+        let input = "[workspace]\nmembers = [\"cli\"]\n\n[workspace.package]\nversion = \"1.2.3\"\n";
+
+        let errors = validate_cargo_toml(input).expect("it not to raise");
+
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn it_should_flag_inherited_version_without_workspace_package_version() {
+        let input = "[workspace]\nmembers = [\"cli\"]\n\n[package]\nname = \"x\"\nversion.workspace = true\n";
+
+        let errors = validate_cargo_toml(input).expect("it not to raise");
+
+        assert!(matches!(
+            errors.as_slice(),
+            [CargoTomlError::MissingWorkspacePackageVersionForInheritance]
+        ));
+    }
+
+    #[test]
+    fn it_should_allow_inherited_version_with_workspace_package_version() {
+        let input = "[workspace]\nmembers = [\"cli\"]\n\n[workspace.package]\nversion = \"1.2.3\"\n\n[package]\nname = \"x\"\nversion.workspace = true\n";
+
+        let errors = validate_cargo_toml(input).expect("it not to raise");
+
+        assert!(errors.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod test_set_cargo_toml_dependency_version {
+    use super::set_cargo_toml_dependency_version;
+
+    #[test]
+    fn it_should_keep_caret_operator() {
+        let input = "[dependencies]\nmy-lib = \"^1.2.3\"\n";
+
+        let (modified, output) =
+            set_cargo_toml_dependency_version(input.to_string(), "my-lib", "1.3.0")
+                .expect("it not to raise");
+
+        assert!(modified);
+
+        assert!(output.contains("my-lib = \"^1.3.0\""));
+    }
+
+    #[test]
+    fn it_should_keep_tilde_and_exact_operators() {
+        let input =
+            "[dependencies]\nmy-lib = \"~1.2.3\"\nother-lib = \"=1.2.3\"\nbare-lib = \"1.2.3\"\n";
+
+        let (modified, output) =
+            set_cargo_toml_dependency_version(input.to_string(), "my-lib", "1.3.0")
+                .expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("my-lib = \"~1.3.0\""));
+
+        let (modified, output) =
+            set_cargo_toml_dependency_version(output, "other-lib", "1.3.0").expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("other-lib = \"=1.3.0\""));
+
+        let (modified, output) =
+            set_cargo_toml_dependency_version(output, "bare-lib", "1.3.0").expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("bare-lib = \"1.3.0\""));
+    }
+
+    #[test]
+    fn it_should_update_inline_table_version_and_keep_other_keys() {
+        let input = "[dependencies]\nmy-lib = { version = \"1.2.3\", path = \"../my-lib\" }\n";
+
+        let (modified, output) =
+            set_cargo_toml_dependency_version(input.to_string(), "my-lib", "1.3.0")
+                .expect("it not to raise");
+
+        assert!(modified);
+
+        assert!(output.contains("version = \"1.3.0\""));
+        assert!(output.contains("path = \"../my-lib\""));
+    }
+
+    #[test]
+    fn it_should_update_workspace_dependencies() {
+        let input = "[workspace.dependencies]\nmy-lib = \"1.2.3\"\n";
+
+        let (modified, output) =
+            set_cargo_toml_dependency_version(input.to_string(), "my-lib", "1.3.0")
+                .expect("it not to raise");
+
+        assert!(modified);
+
+        assert!(output.contains("my-lib = \"1.3.0\""));
+    }
+
+    #[test]
+    fn it_should_not_modify_when_dependency_is_absent() {
+        let input = "[dependencies]\nother-lib = \"1.2.3\"\n";
+
+        let (modified, output) =
+            set_cargo_toml_dependency_version(input.to_string(), "my-lib", "1.3.0")
+                .expect("it not to raise");
+
+        assert!(!modified);
+        assert_eq!(output, input);
+    }
+}
+
 #[cfg(test)]
 mod test_set_cargo_toml_version {
     use super::{CargoTomlError, set_cargo_toml_version};
@@ -258,6 +879,22 @@ toml_edit = "0.22.26"
         }
     }
 
+    #[test]
+    fn it_should_treat_workspace_inherited_version_as_unmodified() {
+        let input = r#"[package]
+name = "my-lib"
+version.workspace = true
+edition.workspace = true
+"#;
+
+        let (modified, output) =
+            set_cargo_toml_version(input.to_string(), "1.23.4").expect("it not to raise");
+
+        assert!(!modified);
+
+        assert_eq!(output, input);
+    }
+
     #[test]
     fn it_should_require_package_field() {
         let input = "";
@@ -417,8 +1054,6 @@ toml_edit = "0.22.26"
         let result = set_cargo_toml_version(input.to_string(), "1.23.4")
             .expect_err("it should return an error");
 
-        dbg!(&result);
-
         assert!(matches!(
             result,
             CargoTomlError::InvalidPackageVersionFieldDataType { workspace: true }
@@ -0,0 +1,187 @@
+#[derive(Debug)]
+pub enum ShardYmlError {
+    InvalidCurrentVersion(crate::bump::BumpError),
+    InvalidVersionFieldDataType,
+    MissingVersionField,
+    ParseYaml(serde_yaml::Error),
+}
+
+impl core::error::Error for ShardYmlError {}
+
+impl core::fmt::Display for ShardYmlError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ParseYaml(error) => error.fmt(f),
+            Self::InvalidCurrentVersion(error) => error.fmt(f),
+            Self::InvalidVersionFieldDataType => write!(f, "\"version\" field is not a string"),
+            Self::MissingVersionField => write!(f, "\"version\" field not found"),
+        }
+    }
+}
+
+#[inline]
+pub fn get_shard_yml_version(contents: &str) -> Result<Option<String>, ShardYmlError> {
+    let document: serde_yaml::Value =
+        serde_yaml::from_str(contents).map_err(ShardYmlError::ParseYaml)?;
+
+    match document.get("version") {
+        None => Ok(None),
+        Some(version_value) => {
+            let version = version_value
+                .as_str()
+                .ok_or(ShardYmlError::InvalidVersionFieldDataType)?;
+
+            Ok(Some(version.to_owned()))
+        }
+    }
+}
+
+#[inline]
+pub fn set_shard_yml_version(
+    contents: String,
+    version: &str,
+) -> Result<(bool, String), ShardYmlError> {
+    let mut found_version_field = false;
+    let mut modified = false;
+
+    let lines: Vec<String> = contents
+        .lines()
+        .map(|line| {
+            let Some(current) = line.strip_prefix("version:") else {
+                return line.to_string();
+            };
+
+            found_version_field = true;
+
+            let current = current.trim();
+
+            if current == version {
+                return line.to_string();
+            }
+
+            modified = true;
+
+            format!("version: {version}")
+        })
+        .collect();
+
+    if !found_version_field {
+        return Err(ShardYmlError::MissingVersionField);
+    }
+
+    if !modified {
+        return Ok((false, contents));
+    }
+
+    let mut output = lines.join("\n");
+
+    if contents.ends_with('\n') {
+        output.push('\n');
+    }
+
+    Ok((true, output))
+}
+
+#[inline]
+pub fn bump_shard_yml_version(
+    contents: String,
+    level: &crate::bump::BumpLevel,
+) -> Result<(bool, String), ShardYmlError> {
+    let current_version =
+        get_shard_yml_version(&contents)?.ok_or(ShardYmlError::MissingVersionField)?;
+
+    let next_version =
+        crate::bump::bump(&current_version, level).map_err(ShardYmlError::InvalidCurrentVersion)?;
+
+    set_shard_yml_version(contents, &next_version)
+}
+
+#[cfg(test)]
+mod test_get_shard_yml_version {
+    use super::{ShardYmlError, get_shard_yml_version};
+
+    #[test]
+    fn it_should_return_shard_version() {
+        let input = "name: my_shard\nversion: 1.2.3\n";
+
+        let version = get_shard_yml_version(input)
+            .expect("it not to raise")
+            .expect("it to return a version");
+
+        assert_eq!(version, "1.2.3");
+    }
+
+    #[test]
+    fn it_should_return_none_when_version_is_missing() {
+        let input = "name: my_shard\n";
+
+        let version = get_shard_yml_version(input).expect("it not to raise");
+
+        assert!(version.is_none());
+    }
+
+    #[test]
+    fn it_should_require_version_to_be_a_string() {
+        let input = "version:\n  key: 123\n";
+
+        let result = get_shard_yml_version(input).expect_err("it should return an error");
+
+        assert!(matches!(
+            result,
+            ShardYmlError::InvalidVersionFieldDataType
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_set_shard_yml_version {
+    use super::{ShardYmlError, set_shard_yml_version};
+
+    #[test]
+    fn it_should_modify_version() {
+        let input = "name: my_shard\nversion: 1.2.3\n";
+
+        let (modified, output) =
+            set_shard_yml_version(input.to_string(), "1.3.0").expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("version: 1.3.0"));
+
+        // Validate we do not modify file if version is the same
+        {
+            let (modified, output) =
+                set_shard_yml_version(output, "1.3.0").expect("it not to raise");
+
+            assert!(!modified);
+            assert!(output.contains("version: 1.3.0"));
+        }
+    }
+
+    #[test]
+    fn it_should_require_version_field() {
+        let input = "name: my_shard\n";
+
+        let result = set_shard_yml_version(input.to_string(), "1.3.0")
+            .expect_err("it should return an error");
+
+        assert!(matches!(result, ShardYmlError::MissingVersionField));
+    }
+}
+
+#[cfg(test)]
+mod test_bump_shard_yml_version {
+    use super::bump_shard_yml_version;
+    use crate::bump::BumpLevel;
+
+    #[test]
+    fn it_should_bump_patch_version() {
+        let input = "name: my_shard\nversion: 1.2.3\n";
+
+        let (modified, output) =
+            bump_shard_yml_version(input.to_string(), &BumpLevel::Patch).expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("version: 1.2.4"));
+    }
+}
@@ -1,6 +1,7 @@
 use super::{
     cargo::CargoTomlError, crystal::ShardYmlError, gleam::GleamTomlError, lerna::LernaJsonError,
-    npm::PackageJsonError, pubspec::PubspecYamlError, pyproject::PyprojectTomlError,
+    npm::PackageJsonError, pom::PomXmlError, pubspec::PubspecYamlError,
+    pyproject::PyprojectTomlError,
 };
 
 #[derive(Debug)]
@@ -9,6 +10,7 @@ pub enum PackageManagerError {
     LernaJson(LernaJsonError),
     GleamToml(GleamTomlError),
     PackageJson(PackageJsonError),
+    PomXml(PomXmlError),
     PubspecYaml(PubspecYamlError),
     PyprojectToml(PyprojectTomlError),
     ShardYml(ShardYmlError),
@@ -24,6 +26,7 @@ impl core::fmt::Display for PackageManagerError {
             Self::LernaJson(error) => error.fmt(f),
             Self::GleamToml(error) => error.fmt(f),
             Self::PackageJson(error) => error.fmt(f),
+            Self::PomXml(error) => error.fmt(f),
             Self::PubspecYaml(error) => error.fmt(f),
             Self::PyprojectToml(error) => error.fmt(f),
             Self::ShardYml(error) => error.fmt(f),
@@ -66,6 +69,13 @@ impl From<PackageJsonError> for PackageManagerError {
     }
 }
 
+impl From<PomXmlError> for PackageManagerError {
+    #[inline]
+    fn from(value: PomXmlError) -> Self {
+        Self::PomXml(value)
+    }
+}
+
 impl From<GleamTomlError> for PackageManagerError {
     #[inline]
     fn from(value: GleamTomlError) -> Self {
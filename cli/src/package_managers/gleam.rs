@@ -0,0 +1,179 @@
+use crate::parsers::toml;
+
+#[derive(Debug)]
+pub enum GleamTomlError {
+    InvalidCurrentVersion(crate::bump::BumpError),
+    InvalidVersionFieldDataType,
+    MissingVersionField,
+    ParseToml(Box<toml_edit::TomlError>),
+}
+
+impl core::error::Error for GleamTomlError {}
+
+impl core::fmt::Display for GleamTomlError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ParseToml(error) => error.fmt(f),
+            Self::InvalidCurrentVersion(error) => error.fmt(f),
+            Self::InvalidVersionFieldDataType => write!(f, "\"version\" field is not a string"),
+            Self::MissingVersionField => write!(f, "\"version\" field not found"),
+        }
+    }
+}
+
+#[inline]
+pub fn get_gleam_toml_version(contents: &str) -> Result<Option<String>, GleamTomlError> {
+    let document =
+        toml::parse(contents).map_err(|error| GleamTomlError::ParseToml(Box::new(error)))?;
+
+    match document.get("version") {
+        None => Ok(None),
+        Some(version_key) => {
+            let version = version_key
+                .as_str()
+                .ok_or(GleamTomlError::InvalidVersionFieldDataType)?;
+
+            Ok(Some(version.to_owned()))
+        }
+    }
+}
+
+#[inline]
+pub fn set_gleam_toml_version(
+    contents: String,
+    version: &str,
+) -> Result<(bool, String), GleamTomlError> {
+    let mut document =
+        toml::parse(&contents).map_err(|error| GleamTomlError::ParseToml(Box::new(error)))?;
+
+    let version_key = document
+        .get("version")
+        .ok_or(GleamTomlError::MissingVersionField)?;
+
+    let current = version_key
+        .as_str()
+        .ok_or(GleamTomlError::InvalidVersionFieldDataType)?;
+
+    let modified = current != version;
+
+    if modified {
+        document.insert(
+            "version",
+            toml_edit::Item::Value(toml_edit::Value::String(toml_edit::Formatted::new(
+                version.into(),
+            ))),
+        );
+    }
+
+    let output = if modified {
+        toml::serialize(&document)
+    } else {
+        contents
+    };
+
+    Ok((modified, output))
+}
+
+#[inline]
+pub fn bump_gleam_toml_version(
+    contents: String,
+    level: &crate::bump::BumpLevel,
+) -> Result<(bool, String), GleamTomlError> {
+    let current_version =
+        get_gleam_toml_version(&contents)?.ok_or(GleamTomlError::MissingVersionField)?;
+
+    let next_version =
+        crate::bump::bump(&current_version, level).map_err(GleamTomlError::InvalidCurrentVersion)?;
+
+    set_gleam_toml_version(contents, &next_version)
+}
+
+#[cfg(test)]
+mod test_get_gleam_toml_version {
+    use super::{GleamTomlError, get_gleam_toml_version};
+
+    #[test]
+    fn it_should_return_gleam_version() {
+        let input = "name = \"my_app\"\nversion = \"1.2.3\"\n";
+
+        let version = get_gleam_toml_version(input)
+            .expect("it not to raise")
+            .expect("it to return a version");
+
+        assert_eq!(version, "1.2.3");
+    }
+
+    #[test]
+    fn it_should_return_none_when_version_is_missing() {
+        let input = "name = \"my_app\"\n";
+
+        let version = get_gleam_toml_version(input).expect("it not to raise");
+
+        assert!(version.is_none());
+    }
+
+    #[test]
+    fn it_should_require_version_to_be_a_string() {
+        let input = "[version]\nkey = \"123\"\n";
+
+        let result = get_gleam_toml_version(input).expect_err("it should return an error");
+
+        assert!(matches!(
+            result,
+            GleamTomlError::InvalidVersionFieldDataType
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_set_gleam_toml_version {
+    use super::{GleamTomlError, set_gleam_toml_version};
+
+    #[test]
+    fn it_should_modify_version() {
+        let input = "name = \"my_app\"\nversion = \"1.2.3\"\n";
+
+        let (modified, output) =
+            set_gleam_toml_version(input.to_string(), "1.3.0").expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("version = \"1.3.0\""));
+
+        // Validate we do not modify file if version is the same
+        {
+            let (modified, output) =
+                set_gleam_toml_version(output, "1.3.0").expect("it not to raise");
+
+            assert!(!modified);
+            assert!(output.contains("version = \"1.3.0\""));
+        }
+    }
+
+    #[test]
+    fn it_should_require_version_field() {
+        let input = "name = \"my_app\"\n";
+
+        let result = set_gleam_toml_version(input.to_string(), "1.3.0")
+            .expect_err("it should return an error");
+
+        assert!(matches!(result, GleamTomlError::MissingVersionField));
+    }
+}
+
+#[cfg(test)]
+mod test_bump_gleam_toml_version {
+    use super::bump_gleam_toml_version;
+    use crate::bump::BumpLevel;
+
+    #[test]
+    fn it_should_bump_patch_version() {
+        let input = "name = \"my_app\"\nversion = \"1.2.3\"\n";
+
+        let (modified, output) =
+            bump_gleam_toml_version(input.to_string(), &BumpLevel::Patch).expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("version = \"1.2.4\""));
+    }
+}
@@ -0,0 +1,190 @@
+#[derive(Debug)]
+pub enum LernaJsonError {
+    InvalidCurrentVersion(crate::bump::BumpError),
+    InvalidVersionFieldDataType,
+    MissingVersionField,
+    ParseJson(serde_json::Error),
+    SerializeJson(serde_json::Error),
+}
+
+impl core::error::Error for LernaJsonError {}
+
+impl core::fmt::Display for LernaJsonError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ParseJson(error) | Self::SerializeJson(error) => error.fmt(f),
+            Self::InvalidCurrentVersion(error) => error.fmt(f),
+            Self::InvalidVersionFieldDataType => write!(f, "\"version\" field is not a string"),
+            Self::MissingVersionField => write!(f, "\"version\" field not found"),
+        }
+    }
+}
+
+#[inline]
+pub fn get_lerna_json_version(contents: &str) -> Result<Option<String>, LernaJsonError> {
+    let document: serde_json::Value =
+        serde_json::from_str(contents).map_err(LernaJsonError::ParseJson)?;
+
+    match document.get("version") {
+        None => Ok(None),
+        Some(version_value) => {
+            let version = version_value
+                .as_str()
+                .ok_or(LernaJsonError::InvalidVersionFieldDataType)?;
+
+            Ok(Some(version.to_owned()))
+        }
+    }
+}
+
+#[inline]
+pub fn set_lerna_json_version(
+    contents: String,
+    version: &str,
+) -> Result<(bool, String), LernaJsonError> {
+    let mut document: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(&contents).map_err(LernaJsonError::ParseJson)?;
+
+    let current = document
+        .get("version")
+        .ok_or(LernaJsonError::MissingVersionField)?;
+
+    let current_str = current
+        .as_str()
+        .ok_or(LernaJsonError::InvalidVersionFieldDataType)?;
+
+    let modified = current_str != version;
+
+    if modified {
+        document.insert(
+            "version".to_string(),
+            serde_json::Value::String(version.to_string()),
+        );
+    }
+
+    let output = if modified {
+        let mut serialized =
+            serde_json::to_string_pretty(&document).map_err(LernaJsonError::SerializeJson)?;
+        serialized.push('\n');
+        serialized
+    } else {
+        contents
+    };
+
+    Ok((modified, output))
+}
+
+#[inline]
+pub fn bump_lerna_json_version(
+    contents: String,
+    level: &crate::bump::BumpLevel,
+) -> Result<(bool, String), LernaJsonError> {
+    let current_version =
+        get_lerna_json_version(&contents)?.ok_or(LernaJsonError::MissingVersionField)?;
+
+    let next_version =
+        crate::bump::bump(&current_version, level).map_err(LernaJsonError::InvalidCurrentVersion)?;
+
+    set_lerna_json_version(contents, &next_version)
+}
+
+#[cfg(test)]
+mod test_get_lerna_json_version {
+    use super::{LernaJsonError, get_lerna_json_version};
+
+    #[test]
+    fn it_should_return_lerna_version() {
+        let input = "{\"version\": \"1.2.3\", \"packages\": [\"packages/*\"]}";
+
+        let version = get_lerna_json_version(input)
+            .expect("it not to raise")
+            .expect("it to return a version");
+
+        assert_eq!(version, "1.2.3");
+    }
+
+    #[test]
+    fn it_should_return_none_when_version_is_missing() {
+        let input = "{\"packages\": [\"packages/*\"]}";
+
+        let version = get_lerna_json_version(input).expect("it not to raise");
+
+        assert!(version.is_none());
+    }
+
+    #[test]
+    fn it_should_support_independent_versioning_sentinel() {
+        let input = "{\"version\": \"independent\"}";
+
+        let version = get_lerna_json_version(input)
+            .expect("it not to raise")
+            .expect("it to return a version");
+
+        assert_eq!(version, "independent");
+    }
+
+    #[test]
+    fn it_should_require_version_to_be_a_string() {
+        let input = "{\"version\": 123}";
+
+        let result = get_lerna_json_version(input).expect_err("it should return an error");
+
+        assert!(matches!(
+            result,
+            LernaJsonError::InvalidVersionFieldDataType
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_set_lerna_json_version {
+    use super::{LernaJsonError, set_lerna_json_version};
+
+    #[test]
+    fn it_should_modify_version() {
+        let input = "{\n  \"version\": \"1.2.3\",\n  \"packages\": [\"packages/*\"]\n}";
+
+        let (modified, output) =
+            set_lerna_json_version(input.to_string(), "1.3.0").expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("\"version\": \"1.3.0\""));
+
+        // Validate we do not modify file if version is the same
+        {
+            let (modified, output) =
+                set_lerna_json_version(output, "1.3.0").expect("it not to raise");
+
+            assert!(!modified);
+            assert!(output.contains("\"version\": \"1.3.0\""));
+        }
+    }
+
+    #[test]
+    fn it_should_require_version_field() {
+        let input = "{\"packages\": [\"packages/*\"]}";
+
+        let result = set_lerna_json_version(input.to_string(), "1.3.0")
+            .expect_err("it should return an error");
+
+        assert!(matches!(result, LernaJsonError::MissingVersionField));
+    }
+}
+
+#[cfg(test)]
+mod test_bump_lerna_json_version {
+    use super::bump_lerna_json_version;
+    use crate::bump::BumpLevel;
+
+    #[test]
+    fn it_should_bump_patch_version() {
+        let input = "{\n  \"version\": \"1.2.3\",\n  \"packages\": [\"packages/*\"]\n}";
+
+        let (modified, output) =
+            bump_lerna_json_version(input.to_string(), &BumpLevel::Patch).expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("\"version\": \"1.2.4\""));
+    }
+}
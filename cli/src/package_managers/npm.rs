@@ -0,0 +1,305 @@
+#[derive(Debug)]
+pub enum PackageJsonError {
+    InvalidCurrentVersion(crate::bump::BumpError),
+    InvalidVersionFieldDataType,
+    MissingVersionField,
+    ParseJson(serde_json::Error),
+    SerializeJson(serde_json::Error),
+}
+
+impl core::error::Error for PackageJsonError {}
+
+impl core::fmt::Display for PackageJsonError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ParseJson(error) | Self::SerializeJson(error) => error.fmt(f),
+            Self::InvalidCurrentVersion(error) => error.fmt(f),
+            Self::InvalidVersionFieldDataType => write!(f, "\"version\" field is not a string"),
+            Self::MissingVersionField => write!(f, "\"version\" field not found"),
+        }
+    }
+}
+
+const DEPENDENCY_FIELD_KEYS: [&str; 4] = [
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+];
+
+#[inline]
+fn reapply_requirement_operator(current: &str, version: &str) -> String {
+    for operator in ["^", "~"] {
+        if current.starts_with(operator) {
+            return format!("{operator}{version}");
+        }
+    }
+
+    version.to_string()
+}
+
+#[inline]
+fn set_dependency_version_in_object(
+    object: &mut serde_json::Map<String, serde_json::Value>,
+    dependency: &str,
+    version: &str,
+) -> bool {
+    let Some(entry) = object.get_mut(dependency) else {
+        return false;
+    };
+
+    let Some(current) = entry.as_str() else {
+        return false;
+    };
+
+    let next = reapply_requirement_operator(current, version);
+
+    if next == current {
+        return false;
+    }
+
+    *entry = serde_json::Value::String(next);
+
+    true
+}
+
+#[inline]
+pub fn set_package_json_dependency_version(
+    contents: String,
+    dependency: &str,
+    version: &str,
+) -> Result<(bool, String), PackageJsonError> {
+    let mut document: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(&contents).map_err(PackageJsonError::ParseJson)?;
+
+    let mut modified = false;
+
+    for key in DEPENDENCY_FIELD_KEYS {
+        if let Some(object) = document
+            .get_mut(key)
+            .and_then(serde_json::Value::as_object_mut)
+        {
+            modified |= set_dependency_version_in_object(object, dependency, version);
+        }
+    }
+
+    let output = if modified {
+        let mut serialized =
+            serde_json::to_string_pretty(&document).map_err(PackageJsonError::SerializeJson)?;
+        serialized.push('\n');
+        serialized
+    } else {
+        contents
+    };
+
+    Ok((modified, output))
+}
+
+#[inline]
+pub fn get_package_json_version(contents: &str) -> Result<Option<String>, PackageJsonError> {
+    let document: serde_json::Value =
+        serde_json::from_str(contents).map_err(PackageJsonError::ParseJson)?;
+
+    match document.get("version") {
+        None => Ok(None),
+        Some(version_value) => {
+            let version = version_value
+                .as_str()
+                .ok_or(PackageJsonError::InvalidVersionFieldDataType)?;
+
+            Ok(Some(version.to_owned()))
+        }
+    }
+}
+
+#[inline]
+pub fn set_package_json_version(
+    contents: String,
+    version: &str,
+) -> Result<(bool, String), PackageJsonError> {
+    let mut document: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(&contents).map_err(PackageJsonError::ParseJson)?;
+
+    let current = document
+        .get("version")
+        .ok_or(PackageJsonError::MissingVersionField)?;
+
+    let current_str = current
+        .as_str()
+        .ok_or(PackageJsonError::InvalidVersionFieldDataType)?;
+
+    let modified = current_str != version;
+
+    if modified {
+        document.insert(
+            "version".to_string(),
+            serde_json::Value::String(version.to_string()),
+        );
+    }
+
+    let output = if modified {
+        let mut serialized =
+            serde_json::to_string_pretty(&document).map_err(PackageJsonError::SerializeJson)?;
+        serialized.push('\n');
+        serialized
+    } else {
+        contents
+    };
+
+    Ok((modified, output))
+}
+
+#[inline]
+pub fn bump_package_json_version(
+    contents: String,
+    level: &crate::bump::BumpLevel,
+) -> Result<(bool, String), PackageJsonError> {
+    let current_version =
+        get_package_json_version(&contents)?.ok_or(PackageJsonError::MissingVersionField)?;
+
+    let next_version = crate::bump::bump(&current_version, level)
+        .map_err(PackageJsonError::InvalidCurrentVersion)?;
+
+    set_package_json_version(contents, &next_version)
+}
+
+#[cfg(test)]
+mod test_get_package_json_version {
+    use super::{PackageJsonError, get_package_json_version};
+
+    #[test]
+    fn it_should_return_package_version() {
+        let input = "{\"name\": \"my-lib\", \"version\": \"1.2.3\"}";
+
+        let version = get_package_json_version(input)
+            .expect("it not to raise")
+            .expect("it to return a version");
+
+        assert_eq!(version, "1.2.3");
+    }
+
+    #[test]
+    fn it_should_return_none_when_version_is_missing() {
+        let input = "{\"name\": \"my-lib\"}";
+
+        let version = get_package_json_version(input).expect("it not to raise");
+
+        assert!(version.is_none());
+    }
+
+    #[test]
+    fn it_should_require_version_to_be_a_string() {
+        let input = "{\"version\": 123}";
+
+        let result = get_package_json_version(input).expect_err("it should return an error");
+
+        assert!(matches!(
+            result,
+            PackageJsonError::InvalidVersionFieldDataType
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_invalid_json() {
+        let input = "not json";
+
+        assert!(get_package_json_version(input).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_set_package_json_version {
+    use super::{PackageJsonError, set_package_json_version};
+
+    #[test]
+    fn it_should_modify_version() {
+        let input = "{\n  \"name\": \"my-lib\",\n  \"version\": \"1.2.3\"\n}";
+
+        let (modified, output) =
+            set_package_json_version(input.to_string(), "1.3.0").expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("\"version\": \"1.3.0\""));
+
+        // Validate we do not modify file if version is the same
+        {
+            let (modified, output) =
+                set_package_json_version(output, "1.3.0").expect("it not to raise");
+
+            assert!(!modified);
+            assert!(output.contains("\"version\": \"1.3.0\""));
+        }
+    }
+
+    #[test]
+    fn it_should_require_version_field() {
+        let input = "{\"name\": \"my-lib\"}";
+
+        let result = set_package_json_version(input.to_string(), "1.3.0")
+            .expect_err("it should return an error");
+
+        assert!(matches!(result, PackageJsonError::MissingVersionField));
+    }
+}
+
+#[cfg(test)]
+mod test_bump_package_json_version {
+    use super::bump_package_json_version;
+    use crate::bump::BumpLevel;
+
+    #[test]
+    fn it_should_bump_patch_version() {
+        let input = "{\n  \"name\": \"my-lib\",\n  \"version\": \"1.2.3\"\n}";
+
+        let (modified, output) =
+            bump_package_json_version(input.to_string(), &BumpLevel::Patch).expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("\"version\": \"1.2.4\""));
+    }
+}
+
+#[cfg(test)]
+mod test_set_package_json_dependency_version {
+    use super::set_package_json_dependency_version;
+
+    #[test]
+    fn it_should_keep_caret_operator() {
+        let input = "{\n  \"dependencies\": {\n    \"@scope/pkg\": \"^1.2.3\"\n  }\n}";
+
+        let (modified, output) =
+            set_package_json_dependency_version(input.to_string(), "@scope/pkg", "1.3.0")
+                .expect("it not to raise");
+
+        assert!(modified);
+
+        assert!(output.contains("\"@scope/pkg\": \"^1.3.0\""));
+    }
+
+    #[test]
+    fn it_should_update_dev_and_peer_dependencies() {
+        let input = "{\n  \"devDependencies\": {\n    \"my-lib\": \"1.2.3\"\n  },\n  \"peerDependencies\": {\n    \"my-lib\": \"~1.2.3\"\n  }\n}";
+
+        let (modified, output) =
+            set_package_json_dependency_version(input.to_string(), "my-lib", "1.3.0")
+                .expect("it not to raise");
+
+        assert!(modified);
+
+        assert!(output.contains("\"devDependencies\": {\n    \"my-lib\": \"1.3.0\""));
+        assert!(output.contains("\"peerDependencies\": {\n    \"my-lib\": \"~1.3.0\""));
+    }
+
+    #[test]
+    fn it_should_not_modify_when_dependency_is_absent() {
+        let input = "{\"dependencies\": {\"other-lib\": \"1.2.3\"}}";
+
+        let (modified, output) =
+            set_package_json_dependency_version(input.to_string(), "my-lib", "1.3.0")
+                .expect("it not to raise");
+
+        assert!(!modified);
+        assert_eq!(output, input);
+    }
+}
@@ -0,0 +1,228 @@
+use quick_xml::events::{BytesText, Event};
+use quick_xml::reader::Reader;
+use quick_xml::writer::Writer;
+
+#[derive(Debug)]
+pub enum PomXmlError {
+    InvalidCurrentVersion(crate::bump::BumpError),
+    MissingVersionElement,
+    ParseXml(quick_xml::Error),
+    InvalidUtf8(std::string::FromUtf8Error),
+}
+
+impl core::error::Error for PomXmlError {}
+
+impl core::fmt::Display for PomXmlError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidCurrentVersion(error) => error.fmt(f),
+            Self::MissingVersionElement => write!(f, "\"project.version\" field not found"),
+            Self::ParseXml(error) => error.fmt(f),
+            Self::InvalidUtf8(error) => error.fmt(f),
+        }
+    }
+}
+
+#[inline]
+fn is_project_version_path(stack: &[String]) -> bool {
+    matches!(stack, [root, tag] if root == "project" && tag == "version")
+}
+
+#[inline]
+pub fn get_pom_xml_version(contents: &str) -> Result<Option<String>, PomXmlError> {
+    let mut reader = Reader::from_str(contents);
+    reader.config_mut().trim_text(false);
+
+    let mut stack: Vec<String> = Vec::new();
+
+    loop {
+        match reader.read_event().map_err(PomXmlError::ParseXml)? {
+            Event::Eof => break,
+            Event::Start(tag) => {
+                stack.push(String::from_utf8_lossy(tag.name().as_ref()).into_owned());
+            }
+            Event::End(_) => {
+                stack.pop();
+            }
+            Event::Text(text) if is_project_version_path(&stack) => {
+                let version = text.unescape().map_err(PomXmlError::ParseXml)?;
+
+                return Ok(Some(version.into_owned()));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(None)
+}
+
+#[inline]
+pub fn set_pom_xml_version(contents: String, version: &str) -> Result<(bool, String), PomXmlError> {
+    let mut reader = Reader::from_str(&contents);
+    reader.config_mut().trim_text(false);
+
+    let mut writer = Writer::new(Vec::with_capacity(contents.len()));
+
+    let mut stack: Vec<String> = Vec::new();
+    let mut modified = false;
+    let mut found_version_element = false;
+
+    loop {
+        let event = reader.read_event().map_err(PomXmlError::ParseXml)?;
+
+        if matches!(event, Event::Eof) {
+            break;
+        }
+
+        match event {
+            Event::Start(ref tag) => {
+                stack.push(String::from_utf8_lossy(tag.name().as_ref()).into_owned());
+            }
+            Event::End(_) => {
+                stack.pop();
+            }
+            _ => {}
+        }
+
+        if let Event::Text(ref text) = event {
+            if is_project_version_path(&stack) {
+                found_version_element = true;
+
+                let current = text.unescape().map_err(PomXmlError::ParseXml)?;
+
+                if current.as_ref() != version {
+                    modified = true;
+
+                    writer
+                        .write_event(Event::Text(BytesText::new(version)))
+                        .map_err(PomXmlError::ParseXml)?;
+
+                    continue;
+                }
+            }
+        }
+
+        writer.write_event(event).map_err(PomXmlError::ParseXml)?;
+    }
+
+    if !found_version_element {
+        return Err(PomXmlError::MissingVersionElement);
+    }
+
+    let output = if modified {
+        String::from_utf8(writer.into_inner()).map_err(PomXmlError::InvalidUtf8)?
+    } else {
+        contents
+    };
+
+    Ok((modified, output))
+}
+
+#[inline]
+pub fn bump_pom_xml_version(
+    contents: String,
+    level: &crate::bump::BumpLevel,
+) -> Result<(bool, String), PomXmlError> {
+    let current_version =
+        get_pom_xml_version(&contents)?.ok_or(PomXmlError::MissingVersionElement)?;
+
+    let next_version =
+        crate::bump::bump(&current_version, level).map_err(PomXmlError::InvalidCurrentVersion)?;
+
+    set_pom_xml_version(contents, &next_version)
+}
+
+#[cfg(test)]
+mod test_pom_xml_version {
+    use super::{get_pom_xml_version, set_pom_xml_version};
+
+    const POM: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0">
+  <parent>
+    <groupId>com.example</groupId>
+    <artifactId>parent-pom</artifactId>
+    <version>0.0.0</version>
+  </parent>
+
+  <groupId>com.example</groupId>
+  <artifactId>my-app</artifactId>
+  <version>1.2.3</version>
+
+  <dependencies>
+    <dependency>
+      <groupId>com.example</groupId>
+      <artifactId>my-lib</artifactId>
+      <version>0.0.0</version>
+    </dependency>
+  </dependencies>
+</project>
+"#;
+
+    #[test]
+    fn it_should_read_project_version() {
+        let version = get_pom_xml_version(POM)
+            .expect("it not to raise")
+            .expect("it to return a version");
+
+        assert_eq!(version, "1.2.3");
+    }
+
+    #[test]
+    fn it_should_modify_project_version_only() {
+        let (modified, output) =
+            set_pom_xml_version(POM.to_string(), "4.5.6").expect("it not to raise");
+
+        assert!(modified);
+
+        assert!(output.contains("<version>4.5.6</version>"));
+
+        // parent and dependency versions must be left untouched
+        assert_eq!(output.matches("<version>0.0.0</version>").count(), 2);
+        assert_eq!(output.matches("<version>4.5.6</version>").count(), 1);
+
+        // Validate we do not modify file if version is the same
+        {
+            let (modified, output) =
+                set_pom_xml_version(output, "4.5.6").expect("it not to raise");
+
+            assert!(!modified);
+
+            assert!(output.contains("<version>4.5.6</version>"));
+        }
+    }
+
+    #[test]
+    fn it_should_require_version_element() {
+        let input = "<project><groupId>com.example</groupId></project>";
+
+        let result =
+            set_pom_xml_version(input.to_string(), "1.0.0").expect_err("it should return an error");
+
+        assert!(matches!(result, super::PomXmlError::MissingVersionElement));
+    }
+}
+
+#[cfg(test)]
+mod test_bump_pom_xml_version {
+    use super::bump_pom_xml_version;
+    use crate::bump::BumpLevel;
+
+    const POM: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0">
+  <groupId>com.example</groupId>
+  <artifactId>my-app</artifactId>
+  <version>1.2.3</version>
+</project>
+"#;
+
+    #[test]
+    fn it_should_bump_patch_version() {
+        let (modified, output) =
+            bump_pom_xml_version(POM.to_string(), &BumpLevel::Patch).expect("it not to raise");
+
+        assert!(modified);
+
+        assert!(output.contains("<version>1.2.4</version>"));
+    }
+}
@@ -0,0 +1,331 @@
+#[derive(Debug)]
+pub enum PubspecYamlError {
+    InvalidCurrentVersion(crate::bump::BumpError),
+    InvalidVersionFieldDataType,
+    MissingVersionField,
+    ParseYaml(serde_yaml::Error),
+}
+
+impl core::error::Error for PubspecYamlError {}
+
+impl core::fmt::Display for PubspecYamlError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ParseYaml(error) => error.fmt(f),
+            Self::InvalidCurrentVersion(error) => error.fmt(f),
+            Self::InvalidVersionFieldDataType => write!(f, "\"version\" field is not a string"),
+            Self::MissingVersionField => write!(f, "\"version\" field not found"),
+        }
+    }
+}
+
+const DEPENDENCY_SECTION_KEYS: [&str; 2] = ["dependencies", "dev_dependencies"];
+
+#[inline]
+fn reapply_requirement_operator(current: &str, version: &str) -> String {
+    if current.starts_with('^') {
+        return format!("^{version}");
+    }
+
+    version.to_string()
+}
+
+#[inline]
+fn dependency_entry_prefix(dependency: &str) -> String {
+    format!("  {dependency}:")
+}
+
+#[inline]
+pub fn set_pubspec_yaml_dependency_version(
+    contents: String,
+    dependency: &str,
+    version: &str,
+) -> Result<(bool, String), PubspecYamlError> {
+    let prefix = dependency_entry_prefix(dependency);
+
+    let mut in_dependency_section = false;
+    let mut modified = false;
+
+    let lines: Vec<String> = contents
+        .lines()
+        .map(|line| {
+            if !line.starts_with(' ') {
+                let section_name = line.trim_end_matches(':');
+
+                in_dependency_section = DEPENDENCY_SECTION_KEYS.contains(&section_name);
+
+                return line.to_string();
+            }
+
+            if !in_dependency_section {
+                return line.to_string();
+            }
+
+            let Some(current) = line.strip_prefix(&prefix) else {
+                return line.to_string();
+            };
+
+            let current = current.trim();
+
+            if current.is_empty() {
+                return line.to_string();
+            }
+
+            let next = reapply_requirement_operator(current, version);
+
+            if next == current {
+                return line.to_string();
+            }
+
+            modified = true;
+
+            format!("{prefix} {next}")
+        })
+        .collect();
+
+    if !modified {
+        return Ok((false, contents));
+    }
+
+    let mut output = lines.join("\n");
+
+    if contents.ends_with('\n') {
+        output.push('\n');
+    }
+
+    Ok((true, output))
+}
+
+#[inline]
+pub fn get_pubspec_yaml_version(contents: &str) -> Result<Option<String>, PubspecYamlError> {
+    let document: serde_yaml::Value =
+        serde_yaml::from_str(contents).map_err(PubspecYamlError::ParseYaml)?;
+
+    match document.get("version") {
+        None => Ok(None),
+        Some(version_value) => {
+            let version = version_value
+                .as_str()
+                .ok_or(PubspecYamlError::InvalidVersionFieldDataType)?;
+
+            Ok(Some(version.to_owned()))
+        }
+    }
+}
+
+#[inline]
+pub fn set_pubspec_yaml_version(
+    contents: String,
+    version: &str,
+) -> Result<(bool, String), PubspecYamlError> {
+    let mut found_version_field = false;
+    let mut modified = false;
+
+    let lines: Vec<String> = contents
+        .lines()
+        .map(|line| {
+            let Some(current) = line.strip_prefix("version:") else {
+                return line.to_string();
+            };
+
+            found_version_field = true;
+
+            let current = current.trim();
+
+            if current == version {
+                return line.to_string();
+            }
+
+            modified = true;
+
+            format!("version: {version}")
+        })
+        .collect();
+
+    if !found_version_field {
+        return Err(PubspecYamlError::MissingVersionField);
+    }
+
+    if !modified {
+        return Ok((false, contents));
+    }
+
+    let mut output = lines.join("\n");
+
+    if contents.ends_with('\n') {
+        output.push('\n');
+    }
+
+    Ok((true, output))
+}
+
+#[inline]
+pub fn bump_pubspec_yaml_version(
+    contents: String,
+    level: &crate::bump::BumpLevel,
+) -> Result<(bool, String), PubspecYamlError> {
+    let current_version =
+        get_pubspec_yaml_version(&contents)?.ok_or(PubspecYamlError::MissingVersionField)?;
+
+    let next_version = crate::bump::bump(&current_version, level)
+        .map_err(PubspecYamlError::InvalidCurrentVersion)?;
+
+    set_pubspec_yaml_version(contents, &next_version)
+}
+
+#[cfg(test)]
+mod test_get_pubspec_yaml_version {
+    use super::{PubspecYamlError, get_pubspec_yaml_version};
+
+    #[test]
+    fn it_should_return_pubspec_version() {
+        let input = "name: my_app\nversion: 1.2.3\n";
+
+        let version = get_pubspec_yaml_version(input)
+            .expect("it not to raise")
+            .expect("it to return a version");
+
+        assert_eq!(version, "1.2.3");
+    }
+
+    #[test]
+    fn it_should_return_none_when_version_is_missing() {
+        let input = "name: my_app\n";
+
+        let version = get_pubspec_yaml_version(input).expect("it not to raise");
+
+        assert!(version.is_none());
+    }
+
+    #[test]
+    fn it_should_require_version_to_be_a_string() {
+        let input = "version:\n  key: 123\n";
+
+        let result = get_pubspec_yaml_version(input).expect_err("it should return an error");
+
+        assert!(matches!(
+            result,
+            PubspecYamlError::InvalidVersionFieldDataType
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_set_pubspec_yaml_dependency_version {
+    use super::set_pubspec_yaml_dependency_version;
+
+    #[test]
+    fn it_should_keep_caret_operator() {
+        let input = "name: my_app\ndependencies:\n  my_lib: ^1.2.3\n";
+
+        let (modified, output) =
+            set_pubspec_yaml_dependency_version(input.to_string(), "my_lib", "1.3.0")
+                .expect("it not to raise");
+
+        assert!(modified);
+
+        assert!(output.contains("  my_lib: ^1.3.0"));
+    }
+
+    #[test]
+    fn it_should_update_dev_dependencies_section() {
+        let input = "name: my_app\ndev_dependencies:\n  my_lib: 1.2.3\n";
+
+        let (modified, output) =
+            set_pubspec_yaml_dependency_version(input.to_string(), "my_lib", "1.3.0")
+                .expect("it not to raise");
+
+        assert!(modified);
+
+        assert!(output.contains("  my_lib: 1.3.0"));
+    }
+
+    #[test]
+    fn it_should_not_modify_nested_path_dependency() {
+        let input = "name: my_app\ndependencies:\n  my_lib:\n    path: ../my_lib\n";
+
+        let (modified, output) =
+            set_pubspec_yaml_dependency_version(input.to_string(), "my_lib", "1.3.0")
+                .expect("it not to raise");
+
+        assert!(!modified);
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn it_should_not_modify_when_dependency_is_absent() {
+        let input = "name: my_app\ndependencies:\n  other_lib: 1.2.3\n";
+
+        let (modified, output) =
+            set_pubspec_yaml_dependency_version(input.to_string(), "my_lib", "1.3.0")
+                .expect("it not to raise");
+
+        assert!(!modified);
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn it_should_not_modify_outside_dependency_sections() {
+        let input = "name: my_app\nmy_lib: 1.2.3\n";
+
+        let (modified, output) =
+            set_pubspec_yaml_dependency_version(input.to_string(), "my_lib", "1.3.0")
+                .expect("it not to raise");
+
+        assert!(!modified);
+        assert_eq!(output, input);
+    }
+}
+
+#[cfg(test)]
+mod test_set_pubspec_yaml_version {
+    use super::{PubspecYamlError, set_pubspec_yaml_version};
+
+    #[test]
+    fn it_should_modify_version() {
+        let input = "name: my_app\nversion: 1.2.3\n";
+
+        let (modified, output) =
+            set_pubspec_yaml_version(input.to_string(), "1.3.0").expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("version: 1.3.0"));
+
+        // Validate we do not modify file if version is the same
+        {
+            let (modified, output) =
+                set_pubspec_yaml_version(output, "1.3.0").expect("it not to raise");
+
+            assert!(!modified);
+            assert!(output.contains("version: 1.3.0"));
+        }
+    }
+
+    #[test]
+    fn it_should_require_version_field() {
+        let input = "name: my_app\n";
+
+        let result = set_pubspec_yaml_version(input.to_string(), "1.3.0")
+            .expect_err("it should return an error");
+
+        assert!(matches!(result, PubspecYamlError::MissingVersionField));
+    }
+}
+
+#[cfg(test)]
+mod test_bump_pubspec_yaml_version {
+    use super::bump_pubspec_yaml_version;
+    use crate::bump::BumpLevel;
+
+    #[test]
+    fn it_should_bump_patch_version() {
+        let input = "name: my_app\nversion: 1.2.3\n";
+
+        let (modified, output) =
+            bump_pubspec_yaml_version(input.to_string(), &BumpLevel::Patch).expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("version: 1.2.4"));
+    }
+}
@@ -0,0 +1,348 @@
+use crate::parsers::toml;
+
+#[derive(Debug)]
+pub enum PyprojectTomlError {
+    InvalidCurrentVersion(crate::bump::BumpError),
+    InvalidPoetryFieldDataType,
+    InvalidProjectFieldDataType,
+    InvalidToolFieldDataType,
+    InvalidVersionFieldDataType,
+    MissingProjectField,
+    MissingVersionField,
+    ParseToml(Box<toml_edit::TomlError>),
+}
+
+impl core::error::Error for PyprojectTomlError {}
+
+impl core::fmt::Display for PyprojectTomlError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ParseToml(error) => error.fmt(f),
+            Self::InvalidCurrentVersion(error) => error.fmt(f),
+            Self::InvalidProjectFieldDataType => write!(f, "\"project\" field is not a table"),
+            Self::InvalidToolFieldDataType => write!(f, "\"tool\" field is not a table"),
+            Self::InvalidPoetryFieldDataType => write!(f, "\"tool.poetry\" field is not a table"),
+            Self::InvalidVersionFieldDataType => write!(f, "\"version\" field is not a string"),
+            Self::MissingProjectField => {
+                write!(f, "neither \"project\" nor \"tool.poetry\" field was found")
+            }
+            Self::MissingVersionField => write!(
+                f,
+                "neither \"project.version\" nor \"tool.poetry.version\" was found"
+            ),
+        }
+    }
+}
+
+#[inline]
+fn get_table_version(
+    table: &dyn toml_edit::TableLike,
+) -> Result<Option<String>, PyprojectTomlError> {
+    match table.get("version") {
+        None => Ok(None),
+        Some(version_key) => {
+            let version = version_key
+                .as_str()
+                .ok_or(PyprojectTomlError::InvalidVersionFieldDataType)?;
+
+            Ok(Some(version.to_owned()))
+        }
+    }
+}
+
+#[inline]
+fn get_poetry_table(
+    document: &toml_edit::DocumentMut,
+) -> Result<Option<&dyn toml_edit::TableLike>, PyprojectTomlError> {
+    let Some(tool) = document.get("tool") else {
+        return Ok(None);
+    };
+
+    let tool_table = tool
+        .as_table_like()
+        .ok_or(PyprojectTomlError::InvalidToolFieldDataType)?;
+
+    let Some(poetry) = tool_table.get("poetry") else {
+        return Ok(None);
+    };
+
+    let poetry_table = poetry
+        .as_table_like()
+        .ok_or(PyprojectTomlError::InvalidPoetryFieldDataType)?;
+
+    Ok(Some(poetry_table))
+}
+
+#[inline]
+pub fn get_pyproject_toml_version(contents: &str) -> Result<Option<String>, PyprojectTomlError> {
+    let document =
+        toml::parse(contents).map_err(|error| PyprojectTomlError::ParseToml(Box::new(error)))?;
+
+    let project = document.get("project");
+
+    if let Some(project) = project {
+        let project_table = project
+            .as_table_like()
+            .ok_or(PyprojectTomlError::InvalidProjectFieldDataType)?;
+
+        if let Some(version) = get_table_version(project_table)? {
+            return Ok(Some(version));
+        }
+    }
+
+    if let Some(poetry_table) = get_poetry_table(&document)? {
+        return get_table_version(poetry_table);
+    }
+
+    if project.is_some() {
+        return Ok(None);
+    }
+
+    Err(PyprojectTomlError::MissingProjectField)
+}
+
+#[inline]
+fn set_table_version(table: &mut dyn toml_edit::TableLike, version: &str) -> bool {
+    let current = table.get("version").and_then(|v| v.as_str());
+
+    if current == Some(version) {
+        return false;
+    }
+
+    table.insert(
+        "version",
+        toml_edit::Item::Value(toml_edit::Value::String(toml_edit::Formatted::new(
+            version.into(),
+        ))),
+    );
+
+    true
+}
+
+#[inline]
+fn get_poetry_table_mut(
+    document: &mut toml_edit::DocumentMut,
+) -> Result<Option<&mut dyn toml_edit::TableLike>, PyprojectTomlError> {
+    let Some(tool) = document.get_mut("tool") else {
+        return Ok(None);
+    };
+
+    let tool_table = tool
+        .as_table_like_mut()
+        .ok_or(PyprojectTomlError::InvalidToolFieldDataType)?;
+
+    let Some(poetry) = tool_table.get_mut("poetry") else {
+        return Ok(None);
+    };
+
+    let poetry_table = poetry
+        .as_table_like_mut()
+        .ok_or(PyprojectTomlError::InvalidPoetryFieldDataType)?;
+
+    Ok(Some(poetry_table))
+}
+
+#[inline]
+pub fn set_pyproject_toml_version(
+    contents: String,
+    version: &str,
+) -> Result<(bool, String), PyprojectTomlError> {
+    let mut document =
+        toml::parse(&contents).map_err(|error| PyprojectTomlError::ParseToml(Box::new(error)))?;
+
+    let project_has_version = match document.get("project") {
+        Some(project) => {
+            let project_table = project
+                .as_table_like()
+                .ok_or(PyprojectTomlError::InvalidProjectFieldDataType)?;
+
+            project_table.get("version").is_some()
+        }
+        None => false,
+    };
+
+    let modified = if project_has_version {
+        let project_table = document
+            .get_mut("project")
+            .and_then(toml_edit::Item::as_table_like_mut)
+            .ok_or(PyprojectTomlError::InvalidProjectFieldDataType)?;
+
+        set_table_version(project_table, version)
+    } else if let Some(poetry_table) = get_poetry_table_mut(&mut document)? {
+        if poetry_table.get("version").is_none() {
+            return Err(PyprojectTomlError::MissingVersionField);
+        }
+
+        set_table_version(poetry_table, version)
+    } else {
+        return Err(PyprojectTomlError::MissingVersionField);
+    };
+
+    let output = if modified {
+        toml::serialize(&document)
+    } else {
+        contents
+    };
+
+    Ok((modified, output))
+}
+
+#[inline]
+pub fn bump_pyproject_toml_version(
+    contents: String,
+    level: &crate::bump::BumpLevel,
+) -> Result<(bool, String), PyprojectTomlError> {
+    let current_version =
+        get_pyproject_toml_version(&contents)?.ok_or(PyprojectTomlError::MissingVersionField)?;
+
+    let next_version = crate::bump::bump(&current_version, level)
+        .map_err(PyprojectTomlError::InvalidCurrentVersion)?;
+
+    set_pyproject_toml_version(contents, &next_version)
+}
+
+#[cfg(test)]
+mod test_get_pyproject_toml_version {
+    use super::{PyprojectTomlError, get_pyproject_toml_version};
+
+    #[test]
+    fn it_should_return_project_version() {
+        let input = "[project]\nname = \"my-lib\"\nversion = \"1.2.3\"\n";
+
+        let version = get_pyproject_toml_version(input)
+            .expect("it not to raise")
+            .expect("it to return a version");
+
+        assert_eq!(version, "1.2.3");
+    }
+
+    #[test]
+    fn it_should_return_none_when_version_is_dynamic() {
+        let input = "[project]\nname = \"my-lib\"\ndynamic = [\"version\"]\n";
+
+        let version = get_pyproject_toml_version(input).expect("it not to raise");
+
+        assert!(version.is_none());
+    }
+
+    #[test]
+    fn it_should_fall_back_to_poetry_version() {
+        let input = "[tool.poetry]\nname = \"my-lib\"\nversion = \"1.2.3\"\n";
+
+        let version = get_pyproject_toml_version(input)
+            .expect("it not to raise")
+            .expect("it to return a version");
+
+        assert_eq!(version, "1.2.3");
+    }
+
+    #[test]
+    fn it_should_prefer_project_version_over_poetry_version() {
+        let input =
+            "[project]\nname = \"my-lib\"\nversion = \"1.2.3\"\n\n[tool.poetry]\nversion = \"0.0.0\"\n";
+
+        let version = get_pyproject_toml_version(input)
+            .expect("it not to raise")
+            .expect("it to return a version");
+
+        assert_eq!(version, "1.2.3");
+    }
+
+    #[test]
+    fn it_should_require_project_or_poetry_field() {
+        let input = "";
+
+        let result = get_pyproject_toml_version(input).expect_err("it should return an error");
+
+        assert!(matches!(result, PyprojectTomlError::MissingProjectField));
+    }
+
+    #[test]
+    fn it_should_require_version_to_be_a_string() {
+        let input = "[project.version]\nkey = \"123\"\n";
+
+        let result = get_pyproject_toml_version(input).expect_err("it should return an error");
+
+        assert!(matches!(
+            result,
+            PyprojectTomlError::InvalidVersionFieldDataType
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_set_pyproject_toml_version {
+    use super::{PyprojectTomlError, set_pyproject_toml_version};
+
+    #[test]
+    fn it_should_modify_project_version() {
+        let input = "[project]\nname = \"my-lib\"\nversion = \"1.2.3\"\n";
+
+        let (modified, output) =
+            set_pyproject_toml_version(input.to_string(), "1.3.0").expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("version = \"1.3.0\""));
+
+        // Validate we do not modify file if version is the same
+        {
+            let (modified, output) =
+                set_pyproject_toml_version(output, "1.3.0").expect("it not to raise");
+
+            assert!(!modified);
+            assert!(output.contains("version = \"1.3.0\""));
+        }
+    }
+
+    #[test]
+    fn it_should_modify_poetry_version_when_project_has_none() {
+        let input = "[tool.poetry]\nname = \"my-lib\"\nversion = \"1.2.3\"\n";
+
+        let (modified, output) =
+            set_pyproject_toml_version(input.to_string(), "1.3.0").expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("version = \"1.3.0\""));
+    }
+
+    #[test]
+    fn it_should_prefer_project_version_over_poetry_version() {
+        let input =
+            "[project]\nname = \"my-lib\"\nversion = \"1.2.3\"\n\n[tool.poetry]\nversion = \"0.0.0\"\n";
+
+        let (modified, output) =
+            set_pyproject_toml_version(input.to_string(), "1.3.0").expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("[project]\nname = \"my-lib\"\nversion = \"1.3.0\""));
+        assert!(output.contains("version = \"0.0.0\""));
+    }
+
+    #[test]
+    fn it_should_require_version_field() {
+        let input = "[project]\nname = \"my-lib\"\ndynamic = [\"version\"]\n";
+
+        let result = set_pyproject_toml_version(input.to_string(), "1.3.0")
+            .expect_err("it should return an error");
+
+        assert!(matches!(result, PyprojectTomlError::MissingVersionField));
+    }
+}
+
+#[cfg(test)]
+mod test_bump_pyproject_toml_version {
+    use super::bump_pyproject_toml_version;
+    use crate::bump::BumpLevel;
+
+    #[test]
+    fn it_should_bump_patch_version() {
+        let input = "[project]\nname = \"my-lib\"\nversion = \"1.2.3\"\n";
+
+        let (modified, output) = bump_pyproject_toml_version(input.to_string(), &BumpLevel::Patch)
+            .expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("version = \"1.2.4\""));
+    }
+}
@@ -0,0 +1,158 @@
+use super::cargo::{bump_cargo_toml_version, get_cargo_toml_version};
+use super::crystal::{bump_shard_yml_version, get_shard_yml_version};
+use super::error::PackageManagerError;
+use super::gleam::{bump_gleam_toml_version, get_gleam_toml_version};
+use super::lerna::{bump_lerna_json_version, get_lerna_json_version};
+use super::npm::{bump_package_json_version, get_package_json_version};
+use super::pom::{bump_pom_xml_version, get_pom_xml_version};
+use super::pubspec::{bump_pubspec_yaml_version, get_pubspec_yaml_version};
+use super::pyproject::{bump_pyproject_toml_version, get_pyproject_toml_version};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestKind {
+    CargoToml,
+    GleamToml,
+    LernaJson,
+    PackageJson,
+    PomXml,
+    PubspecYaml,
+    PyprojectToml,
+    ShardYml,
+}
+
+#[inline]
+pub fn get_manifest_version(
+    kind: ManifestKind,
+    contents: &str,
+) -> Result<Option<String>, PackageManagerError> {
+    let version = match kind {
+        ManifestKind::CargoToml => get_cargo_toml_version(contents)?,
+        ManifestKind::GleamToml => get_gleam_toml_version(contents)?,
+        ManifestKind::LernaJson => get_lerna_json_version(contents)?,
+        ManifestKind::PackageJson => get_package_json_version(contents)?,
+        ManifestKind::PomXml => get_pom_xml_version(contents)?,
+        ManifestKind::PubspecYaml => get_pubspec_yaml_version(contents)?,
+        ManifestKind::PyprojectToml => get_pyproject_toml_version(contents)?,
+        ManifestKind::ShardYml => get_shard_yml_version(contents)?,
+    };
+
+    Ok(version)
+}
+
+#[inline]
+pub fn bump_manifest_version(
+    kind: ManifestKind,
+    contents: String,
+    level: &crate::bump::BumpLevel,
+) -> Result<(bool, String), PackageManagerError> {
+    let result = match kind {
+        ManifestKind::CargoToml => bump_cargo_toml_version(contents, level)?,
+        ManifestKind::GleamToml => bump_gleam_toml_version(contents, level)?,
+        ManifestKind::LernaJson => bump_lerna_json_version(contents, level)?,
+        ManifestKind::PackageJson => bump_package_json_version(contents, level)?,
+        ManifestKind::PomXml => bump_pom_xml_version(contents, level)?,
+        ManifestKind::PubspecYaml => bump_pubspec_yaml_version(contents, level)?,
+        ManifestKind::PyprojectToml => bump_pyproject_toml_version(contents, level)?,
+        ManifestKind::ShardYml => bump_shard_yml_version(contents, level)?,
+    };
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test_get_manifest_version {
+    use super::{ManifestKind, get_manifest_version};
+
+    #[test]
+    fn it_should_dispatch_to_cargo_toml() {
+        let input = "[package]\nversion = \"1.2.3\"\n";
+
+        let version = get_manifest_version(ManifestKind::CargoToml, input)
+            .expect("it not to raise")
+            .expect("it to return a version");
+
+        assert_eq!(version, "1.2.3");
+    }
+
+    #[test]
+    fn it_should_dispatch_to_package_json() {
+        let input = "{\"version\": \"1.2.3\"}";
+
+        let version = get_manifest_version(ManifestKind::PackageJson, input)
+            .expect("it not to raise")
+            .expect("it to return a version");
+
+        assert_eq!(version, "1.2.3");
+    }
+
+    #[test]
+    fn it_should_dispatch_to_pubspec_yaml() {
+        let input = "version: 1.2.3\n";
+
+        let version = get_manifest_version(ManifestKind::PubspecYaml, input)
+            .expect("it not to raise")
+            .expect("it to return a version");
+
+        assert_eq!(version, "1.2.3");
+    }
+
+    #[test]
+    fn it_should_surface_format_specific_errors() {
+        let input = "not json";
+
+        let result = get_manifest_version(ManifestKind::PackageJson, input);
+
+        assert!(result.is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_bump_manifest_version {
+    use super::{ManifestKind, bump_manifest_version};
+    use crate::bump::BumpLevel;
+
+    #[test]
+    fn it_should_dispatch_to_cargo_toml() {
+        let input = "[package]\nversion = \"1.2.3\"\n";
+
+        let (modified, output) =
+            bump_manifest_version(ManifestKind::CargoToml, input.to_string(), &BumpLevel::Patch)
+                .expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("version = \"1.2.4\""));
+    }
+
+    #[test]
+    fn it_should_dispatch_to_package_json() {
+        let input = "{\"version\": \"1.2.3\"}";
+
+        let (modified, output) =
+            bump_manifest_version(ManifestKind::PackageJson, input.to_string(), &BumpLevel::Patch)
+                .expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("\"version\": \"1.2.4\""));
+    }
+
+    #[test]
+    fn it_should_dispatch_to_pubspec_yaml() {
+        let input = "version: 1.2.3\n";
+
+        let (modified, output) =
+            bump_manifest_version(ManifestKind::PubspecYaml, input.to_string(), &BumpLevel::Patch)
+                .expect("it not to raise");
+
+        assert!(modified);
+        assert!(output.contains("version: 1.2.4"));
+    }
+
+    #[test]
+    fn it_should_surface_format_specific_errors() {
+        let input = "not json".to_string();
+
+        let result = bump_manifest_version(ManifestKind::PackageJson, input, &BumpLevel::Patch);
+
+        assert!(result.is_err());
+    }
+}